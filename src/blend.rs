@@ -0,0 +1,129 @@
+use num_traits::{Float, Zero};
+
+/// A value type that knows how to linearly combine instances of itself.
+///
+/// Implementing `Blend` lets [`crate::IDW`] interpolate values other than a single scalar,
+/// such as RGB colors or 2-D/3-D vectors, by driving a single weight computation across all
+/// of a value's components at once.
+pub trait Blend<N = f64>: Copy
+where
+    N: Float,
+{
+    /// Returns the additive identity, used as the accumulator's starting point.
+    fn zero() -> Self;
+
+    /// Returns the sum of `self` and `rhs`.
+    fn add(&self, rhs: &Self) -> Self;
+
+    /// Returns `self` scaled by `weight`.
+    fn scale(&self, weight: N) -> Self;
+}
+
+macro_rules! impl_blend {
+    ($($t:ty),*) => {
+        $(
+            impl Blend<$t> for $t {
+                fn zero() -> Self {
+                    <$t as Zero>::zero()
+                }
+
+                fn add(&self, rhs: &Self) -> Self {
+                    *self + *rhs
+                }
+
+                fn scale(&self, weight: $t) -> Self {
+                    *self * weight
+                }
+            }
+
+            impl Blend<$t> for ($t, $t) {
+                fn zero() -> Self {
+                    (<$t as Zero>::zero(), <$t as Zero>::zero())
+                }
+
+                fn add(&self, rhs: &Self) -> Self {
+                    (self.0 + rhs.0, self.1 + rhs.1)
+                }
+
+                fn scale(&self, weight: $t) -> Self {
+                    (self.0 * weight, self.1 * weight)
+                }
+            }
+
+            impl Blend<$t> for ($t, $t, $t) {
+                fn zero() -> Self {
+                    (<$t as Zero>::zero(), <$t as Zero>::zero(), <$t as Zero>::zero())
+                }
+
+                fn add(&self, rhs: &Self) -> Self {
+                    (self.0 + rhs.0, self.1 + rhs.1, self.2 + rhs.2)
+                }
+
+                fn scale(&self, weight: $t) -> Self {
+                    (self.0 * weight, self.1 * weight, self.2 * weight)
+                }
+            }
+        )*
+    };
+}
+
+impl_blend!(f32, f64);
+
+impl<T, const K: usize> Blend<T> for [T; K]
+where
+    T: Float,
+{
+    fn zero() -> Self {
+        [T::zero(); K]
+    }
+
+    fn add(&self, rhs: &Self) -> Self {
+        let mut result = [T::zero(); K];
+
+        for i in 0..K {
+            result[i] = self[i] + rhs[i];
+        }
+
+        result
+    }
+
+    fn scale(&self, weight: T) -> Self {
+        let mut result = [T::zero(); K];
+
+        for i in 0..K {
+            result[i] = self[i] * weight;
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blend_scalar() {
+        assert_eq!(<f64 as Blend<f64>>::zero(), 0.0);
+        assert_eq!(2.0.add(&3.0), 5.0);
+        assert_eq!(2.0.scale(3.0), 6.0);
+    }
+
+    #[test]
+    fn test_blend_tuple() {
+        assert_eq!(<(f64, f64) as Blend<f64>>::zero(), (0.0, 0.0));
+        assert_eq!((1.0, 2.0).add(&(3.0, 4.0)), (4.0, 6.0));
+        assert_eq!((1.0, 2.0).scale(2.0), (2.0, 4.0));
+
+        assert_eq!(<(f64, f64, f64) as Blend<f64>>::zero(), (0.0, 0.0, 0.0));
+        assert_eq!((1.0, 2.0, 3.0).add(&(4.0, 5.0, 6.0)), (5.0, 7.0, 9.0));
+        assert_eq!((1.0, 2.0, 3.0).scale(2.0), (2.0, 4.0, 6.0));
+    }
+
+    #[test]
+    fn test_blend_array() {
+        assert_eq!(<[f64; 3] as Blend<f64>>::zero(), [0.0, 0.0, 0.0]);
+        assert_eq!([1.0, 2.0, 3.0].add(&[4.0, 5.0, 6.0]), [5.0, 7.0, 9.0]);
+        assert_eq!([1.0, 2.0, 3.0].scale(2.0), [2.0, 4.0, 6.0]);
+    }
+}
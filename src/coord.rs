@@ -5,6 +5,26 @@ where
     T: Float,
 {
     fn distance_to(&self, rhs: &Self) -> T;
+
+    /// Returns the squared distance between `self` and `rhs`.
+    ///
+    /// Defaults to squaring [`Coord::distance_to`], but implementations should override this
+    /// to skip the square root when the squared distance is cheaper to compute directly.
+    fn distance_squared_to(&self, rhs: &Self) -> T {
+        let d = self.distance_to(rhs);
+
+        d * d
+    }
+
+    /// Returns the number of components (axes) this coordinate has.
+    fn dimensions(&self) -> usize;
+
+    /// Returns the value of the given axis (0-indexed).
+    ///
+    /// # Panics
+    ///
+    /// - `axis` is out of bounds for `self.dimensions()`.
+    fn axis(&self, axis: usize) -> T;
 }
 
 macro_rules! impl_coord {
@@ -14,6 +34,22 @@ macro_rules! impl_coord {
                 fn distance_to(&self, rhs: &Self) -> $t {
                     (*rhs - *self).abs()
                 }
+
+                fn distance_squared_to(&self, rhs: &Self) -> $t {
+                    let d = *rhs - *self;
+                    d * d
+                }
+
+                fn dimensions(&self) -> usize {
+                    1
+                }
+
+                fn axis(&self, axis: usize) -> $t {
+                    match axis {
+                        0 => *self,
+                        _ => panic!("axis {} is out of bounds for a 1-dimensional coordinate", axis),
+                    }
+                }
             }
 
             impl Coord<$t> for ($t, $t) {
@@ -22,6 +58,24 @@ macro_rules! impl_coord {
                     let dy = rhs.1 - self.1;
                     (dx * dx + dy * dy).sqrt()
                 }
+
+                fn distance_squared_to(&self, rhs: &Self) -> $t {
+                    let dx = rhs.0 - self.0;
+                    let dy = rhs.1 - self.1;
+                    dx * dx + dy * dy
+                }
+
+                fn dimensions(&self) -> usize {
+                    2
+                }
+
+                fn axis(&self, axis: usize) -> $t {
+                    match axis {
+                        0 => self.0,
+                        1 => self.1,
+                        _ => panic!("axis {} is out of bounds for a 2-dimensional coordinate", axis),
+                    }
+                }
             }
 
             impl Coord<$t> for ($t, $t, $t) {
@@ -31,6 +85,26 @@ macro_rules! impl_coord {
                     let dz = rhs.2 - self.2;
                     (dx * dx + dy * dy + dz * dz).sqrt()
                 }
+
+                fn distance_squared_to(&self, rhs: &Self) -> $t {
+                    let dx = rhs.0 - self.0;
+                    let dy = rhs.1 - self.1;
+                    let dz = rhs.2 - self.2;
+                    dx * dx + dy * dy + dz * dz
+                }
+
+                fn dimensions(&self) -> usize {
+                    3
+                }
+
+                fn axis(&self, axis: usize) -> $t {
+                    match axis {
+                        0 => self.0,
+                        1 => self.1,
+                        2 => self.2,
+                        _ => panic!("axis {} is out of bounds for a 3-dimensional coordinate", axis),
+                    }
+                }
             }
         )*
     };
@@ -38,6 +112,30 @@ macro_rules! impl_coord {
 
 impl_coord!(f32, f64);
 
+impl<T, const D: usize> Coord<T> for [T; D]
+where
+    T: Float,
+{
+    fn distance_to(&self, rhs: &Self) -> T {
+        self.distance_squared_to(rhs).sqrt()
+    }
+
+    fn distance_squared_to(&self, rhs: &Self) -> T {
+        self.iter().zip(rhs.iter()).fold(T::zero(), |acc, (a, b)| {
+            let d = *b - *a;
+            acc + d * d
+        })
+    }
+
+    fn dimensions(&self) -> usize {
+        D
+    }
+
+    fn axis(&self, axis: usize) -> T {
+        self[axis]
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -68,4 +166,41 @@ mod tests {
         assert_eq!((1.0, 2.0, 2.0).distance_to(&(-1.0, -2.0, -2.0),), 6.0);
         assert_eq!((-1.0, -2.0, -2.0).distance_to(&(1.0, 2.0, 2.0),), 6.0);
     }
+
+    #[test]
+    fn test_distance_to_nd() {
+        assert_eq!([0.0, 0.0, 0.0, 0.0].distance_to(&[1.0, 2.0, 2.0, 0.0]), 3.0);
+        assert_eq!(
+            [1.0, 2.0, 2.0, 0.0].distance_to(&[-1.0, -2.0, -2.0, 0.0]),
+            6.0
+        );
+        assert_eq!([1.0, 1.0].distance_to(&[4.0, 5.0]), 5.0);
+    }
+
+    #[test]
+    fn test_distance_squared_to() {
+        assert_eq!(3.0.distance_squared_to(&4.0), 1.0);
+        assert_eq!((0.0, 0.0).distance_squared_to(&(3.0, 4.0)), 25.0);
+        assert_eq!(
+            (0.0, 0.0, 0.0).distance_squared_to(&(1.0, 2.0, 2.0)),
+            9.0
+        );
+        assert_eq!([0.0, 0.0].distance_squared_to(&[3.0, 4.0]), 25.0);
+    }
+
+    #[test]
+    fn test_dimensions_and_axis() {
+        assert_eq!(3.0.dimensions(), 1);
+        assert_eq!(3.0.axis(0), 3.0);
+
+        assert_eq!((1.0, 2.0).dimensions(), 2);
+        assert_eq!((1.0, 2.0).axis(0), 1.0);
+        assert_eq!((1.0, 2.0).axis(1), 2.0);
+
+        assert_eq!((1.0, 2.0, 3.0).dimensions(), 3);
+        assert_eq!((1.0, 2.0, 3.0).axis(2), 3.0);
+
+        assert_eq!([1.0, 2.0, 3.0, 4.0].dimensions(), 4);
+        assert_eq!([1.0, 2.0, 3.0, 4.0].axis(3), 4.0);
+    }
 }
@@ -1,6 +1,31 @@
 use num_traits::Float;
 
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use crate::blend::Blend;
 use crate::coord::Coord;
+use crate::kdtree::KdTree;
+
+/// Which neighbors [`IDW::evaluate`] should consider, switching the interpolator from global
+/// IDW to Franke-Nielson's localized Modified Shepard scheme.
+enum NeighborStrategy<N> {
+    Radius(N),
+    KNearest(usize),
+}
+
+/// Returns `true` if `value` is an even integer (e.g. `2.0`, `4.0`), in which case the
+/// distance raised to `value` can be computed from the squared distance directly.
+fn is_even_integer<N>(value: N) -> bool
+where
+    N: Float,
+{
+    if !value.fract().is_zero() {
+        return false;
+    }
+
+    (value / (N::one() + N::one())).fract().is_zero()
+}
 
 /// The `IDW` struct represents an Inverse Distance Weighting interpolator.
 ///
@@ -9,21 +34,29 @@ use crate::coord::Coord;
 /// You can transform the weights by setting a transform function by calling [`IDW::weighted_function`].
 ///
 /// The default power parameter used in the algorithm is 2 and can be set by [`IDW::power`].
-pub struct IDW<C, N>
+///
+/// The values being interpolated can be anything implementing [`Blend`], not just a single
+/// scalar, so `IDW` can drive a multi-component value (e.g. an RGB color) from one weight
+/// computation.
+pub struct IDW<C, N, V = N>
 where
     C: Coord<N>,
     N: Float,
+    V: Blend<N>,
 {
     points: Vec<C>,
-    values: Vec<N>,
+    values: Vec<V>,
     power_parameter: N,
-    weighted_function: Option<Box<dyn Fn(N) -> N>>,
+    weighted_function: Option<Box<dyn Fn(N) -> N + Sync>>,
+    kdtree: KdTree,
+    neighbor_strategy: Option<NeighborStrategy<N>>,
 }
 
-impl<C, N> IDW<C, N>
+impl<C, N, V> IDW<C, N, V>
 where
     C: Coord<N>,
     N: Float,
+    V: Blend<N>,
 {
     /// Creates a new instance of the `IDW` struct.
     ///
@@ -41,7 +74,7 @@ where
     /// - Points vector is empty.
     /// - Values vector is empty.
     /// - Points and values vectors have different length.
-    pub fn new(points: Vec<C>, values: Vec<N>) -> Self {
+    pub fn new(points: Vec<C>, values: Vec<V>) -> Self {
         assert_ne!(points.len(), 0, "Points vector must not be empty.");
         assert_ne!(values.len(), 0, "Values vector must not be empty.");
         assert_eq!(
@@ -50,11 +83,15 @@ where
             "Points and values vectors must be the same length."
         );
 
+        let kdtree = KdTree::build(&points);
+
         Self {
             points,
             values,
             power_parameter: N::from(2).unwrap(),
             weighted_function: None,
+            kdtree,
+            neighbor_strategy: None,
         }
     }
 
@@ -75,6 +112,9 @@ where
 
     /// Sets the custom weighted function to be applied to the weights.
     ///
+    /// Only used by the global IDW scheme; it has no effect once [`IDW::search_radius`] or
+    /// [`IDW::k_nearest`] switches `evaluate` to the localized Modified Shepard scheme.
+    ///
     /// # Arguments
     ///
     /// - `func` - A function that takes a weight and returns a new weight.
@@ -82,14 +122,65 @@ where
     /// # Returns
     ///
     /// The modified instance of the struct.
-    pub fn weighted_function(mut self, func: impl Fn(N) -> N + 'static) -> Self {
+    pub fn weighted_function(mut self, func: impl Fn(N) -> N + Sync + 'static) -> Self {
         self.weighted_function = Some(Box::new(func));
 
         self
     }
 
+    /// Restricts `evaluate` to only the points within `radius` of the query position,
+    /// switching the interpolator to Franke-Nielson's localized Modified Shepard scheme
+    /// instead of summing over every point.
+    ///
+    /// If no point falls within `radius`, the single nearest point is used instead.
+    ///
+    /// This supersedes [`IDW::weighted_function`]: once set, `evaluate` no longer calls the
+    /// custom weighted function.
+    ///
+    /// # Arguments
+    ///
+    /// - `radius` - The search radius.
+    ///
+    /// # Returns
+    ///
+    /// The modified instance of the struct.
+    pub fn search_radius(mut self, radius: N) -> Self {
+        self.neighbor_strategy = Some(NeighborStrategy::Radius(radius));
+
+        self
+    }
+
+    /// Restricts `evaluate` to only the `k` points closest to the query position, switching
+    /// the interpolator to Franke-Nielson's localized Modified Shepard scheme instead of
+    /// summing over every point.
+    ///
+    /// This supersedes [`IDW::weighted_function`]: once set, `evaluate` no longer calls the
+    /// custom weighted function.
+    ///
+    /// # Arguments
+    ///
+    /// - `k` - The number of closest points to consider.
+    ///
+    /// # Returns
+    ///
+    /// The modified instance of the struct.
+    ///
+    /// # Panics
+    ///
+    /// - `k` is `0`.
+    pub fn k_nearest(mut self, k: usize) -> Self {
+        assert_ne!(k, 0, "k must not be 0.");
+
+        self.neighbor_strategy = Some(NeighborStrategy::KNearest(k));
+
+        self
+    }
+
     /// Calculates the interpolated value at a given position.
     ///
+    /// When [`IDW::search_radius`] or [`IDW::k_nearest`] has been set, this only considers the
+    /// local neighborhood of `position` instead of summing over every point.
+    ///
     /// # Arguments
     ///
     /// - `position` - The position to evaluate.
@@ -97,19 +188,41 @@ where
     /// # Returns
     ///
     /// The interpolated value at the given position.
-    pub fn evaluate(&self, position: C) -> N {
+    pub fn evaluate(&self, position: C) -> V {
+        if let Some(strategy) = &self.neighbor_strategy {
+            return self.evaluate_localized(position, strategy);
+        }
+
+        let half_power = is_even_integer(self.power_parameter)
+            .then(|| self.power_parameter / (N::one() + N::one()));
+
         let weight_result = self
             .points
             .iter()
             .enumerate()
             .map(|(index, point)| {
-                let distance = point.distance_to(&position);
+                let weight = match half_power {
+                    // When the power is an even integer, the weight can be derived directly
+                    // from the squared distance, skipping the square root in `distance_to`.
+                    Some(half_power) => {
+                        let distance_squared = point.distance_squared_to(&position);
 
-                if distance.is_zero() {
-                    return Err(index);
-                }
+                        if distance_squared.is_zero() {
+                            return Err(index);
+                        }
+
+                        N::one() / distance_squared.powf(half_power)
+                    }
+                    None => {
+                        let distance = point.distance_to(&position);
 
-                let weight = N::one() / distance.powf(self.power_parameter);
+                        if distance.is_zero() {
+                            return Err(index);
+                        }
+
+                        N::one() / distance.powf(self.power_parameter)
+                    }
+                };
 
                 Ok(weight)
             })
@@ -130,7 +243,7 @@ where
                 normalized_weights
                     .iter()
                     .zip(&self.values)
-                    .fold(N::zero(), |acc, (w, v)| acc + *w * *v)
+                    .fold(V::zero(), |acc, (w, v)| acc.add(&v.scale(*w)))
             }
             Err(index) => self.values[index],
         };
@@ -144,6 +257,100 @@ where
             weights.iter().map(|w| *w / weight_sum).collect::<Vec<N>>()
         }
     }
+
+    /// Calculates the interpolated value at `position` using only its local neighborhood, per
+    /// Franke-Nielson's Modified Shepard scheme.
+    fn evaluate_localized(&self, position: C, strategy: &NeighborStrategy<N>) -> V {
+        let indices = match strategy {
+            NeighborStrategy::Radius(radius) => {
+                let within_radius = self.kdtree.within_radius(&self.points, &position, *radius);
+
+                if within_radius.is_empty() {
+                    // No point falls inside the radius: fall back to the single nearest point.
+                    return self.values[self.kdtree.nearest(&self.points, &position)];
+                }
+
+                within_radius
+            }
+            NeighborStrategy::KNearest(k) => self.kdtree.k_nearest(&self.points, &position, *k),
+        };
+
+        let distances: Vec<N> = indices
+            .iter()
+            .map(|&index| self.points[index].distance_to(&position))
+            .collect();
+
+        if let Some(position_in_indices) = distances.iter().position(|distance| distance.is_zero()) {
+            return self.values[indices[position_in_indices]];
+        }
+
+        // For `k_nearest`, there's no fixed search radius, so use the distance to the farthest
+        // of the selected neighbors as the effective radius in the Modified Shepard weight.
+        let radius = match strategy {
+            NeighborStrategy::Radius(radius) => *radius,
+            NeighborStrategy::KNearest(_) => distances
+                .iter()
+                .fold(N::zero(), |max, &distance| max.max(distance)),
+        };
+
+        let weights: Vec<N> = distances
+            .iter()
+            .map(|&distance| {
+                let w = ((radius - distance).max(N::zero())) / (radius * distance);
+
+                w * w
+            })
+            .collect();
+
+        let weight_sum = weights.iter().fold(N::zero(), |acc, w| acc + *w);
+
+        if weight_sum.is_zero() {
+            // Every selected neighbor sits exactly at the effective radius: fall back to the
+            // single nearest point, same as the empty-`within_radius` case above.
+            return self.values[self.kdtree.nearest(&self.points, &position)];
+        }
+
+        weights
+            .iter()
+            .zip(&indices)
+            .fold(V::zero(), |acc, (w, &index)| {
+                acc.add(&self.values[index].scale(*w / weight_sum))
+            })
+    }
+
+    /// Calculates the interpolated values for many positions in parallel.
+    ///
+    /// Batches smaller than `MIN_LEN` are evaluated serially, since spawning work across
+    /// threads costs more than it saves for a handful of positions.
+    ///
+    /// # Arguments
+    ///
+    /// - `positions` - The positions to evaluate.
+    ///
+    /// # Returns
+    ///
+    /// The interpolated values, in the same order as `positions`.
+    #[cfg(feature = "rayon")]
+    pub fn evaluate_many(&self, positions: &[C]) -> Vec<V>
+    where
+        C: Copy + Sync,
+        N: Send + Sync,
+        V: Send + Sync,
+    {
+        const MIN_LEN: usize = 1000;
+
+        if positions.len() < MIN_LEN {
+            positions
+                .iter()
+                .map(|position| self.evaluate(*position))
+                .collect()
+        } else {
+            positions
+                .par_iter()
+                .map(|position| self.evaluate(*position))
+                .collect()
+        }
+    }
 }
 
 #[cfg(test)]
@@ -164,7 +371,7 @@ mod tests {
     #[should_panic]
     fn test_empty_values() {
         let points = vec![1.0, 2.0];
-        let values = vec![];
+        let values: Vec<f64> = vec![];
         IDW::new(points, values);
     }
 
@@ -192,12 +399,28 @@ mod tests {
         assert_relative_eq!(idw.evaluate(4.0), 2.185011, max_relative = 0.000001);
     }
 
+    #[test]
+    fn test_power_even_integer() {
+        let points = vec![1.0, 2.0, 3.0];
+        let values = vec![1.0, 2.0, 3.0];
+        let idw = IDW::new(points, values).power(4.0);
+
+        assert_relative_eq!(idw.evaluate(0.0), 1.081120, max_relative = 0.000001);
+        assert_relative_eq!(idw.evaluate(1.0), 1.0);
+        assert_relative_eq!(idw.evaluate(1.001), 1.000000, max_relative = 0.000001);
+        assert_relative_eq!(idw.evaluate(1.5), 1.509202, max_relative = 0.000001);
+        assert_relative_eq!(idw.evaluate(2.0), 2.0);
+        assert_relative_eq!(idw.evaluate(2.5), 2.490798, max_relative = 0.000001);
+        assert_relative_eq!(idw.evaluate(3.0), 3.0);
+        assert_relative_eq!(idw.evaluate(4.0), 2.918880, max_relative = 0.000001);
+    }
+
     #[test]
     fn test_weighted_function() {
         let points = vec![1.0, 2.0, 3.0];
         let values = vec![1.0, 2.0, 3.0];
         let idw = IDW::new(points, values)
-            .weighted_function(|weight| (1.0 + (4.0 * PI * weight).sin()) * 0.5);
+            .weighted_function(|weight: f64| (1.0 + (4.0 * PI * weight).sin()) * 0.5);
 
         assert_relative_eq!(idw.evaluate(0.0), 2.138717, max_relative = 0.000001);
         assert_relative_eq!(idw.evaluate(1.0), 1.0);
@@ -280,4 +503,85 @@ mod tests {
             max_relative = 0.000001
         );
     }
+
+    #[test]
+    fn test_idw_blend_values() {
+        let points = vec![1.0, 2.0, 3.0];
+        let values = vec![(1.0, 10.0), (2.0, 20.0), (3.0, 30.0)];
+        let idw = IDW::new(points, values);
+
+        let (a, b) = idw.evaluate(0.0);
+        assert_relative_eq!(a, 1.346938, max_relative = 0.000001);
+        assert_relative_eq!(b, 13.46938, max_relative = 0.000001);
+
+        let (a, b) = idw.evaluate(1.5);
+        assert_relative_eq!(a, 1.578947, max_relative = 0.000001);
+        assert_relative_eq!(b, 15.78947, max_relative = 0.000001);
+
+        assert_eq!(idw.evaluate(2.0), (2.0, 20.0));
+    }
+
+    #[test]
+    fn test_search_radius() {
+        let points = vec![1.0, 2.0, 3.0, 100.0];
+        let values = vec![1.0, 2.0, 3.0, 100.0];
+        let idw = IDW::new(points, values).search_radius(2.0);
+
+        // Exact match is returned as-is.
+        assert_relative_eq!(idw.evaluate(2.0), 2.0);
+
+        // Within the radius of points 1.0 and 2.0 but not 3.0 or 100.0.
+        assert_relative_eq!(idw.evaluate(0.5), 1.012195, max_relative = 0.000001);
+
+        // No point within the radius: falls back to the single nearest point (3.0).
+        assert_relative_eq!(idw.evaluate(50.0), 3.0);
+    }
+
+    #[test]
+    fn test_k_nearest() {
+        let points = vec![1.0, 2.0, 3.0, 100.0];
+        let values = vec![1.0, 2.0, 3.0, 100.0];
+        let idw = IDW::new(points, values).k_nearest(2);
+
+        // Exact match is returned as-is.
+        assert_relative_eq!(idw.evaluate(2.0), 2.0);
+
+        // Only points 1.0 and 2.0 (the 2 nearest) influence this query; the farthest of the
+        // selected neighbors sits exactly at the effective search radius, so it contributes
+        // zero weight and the nearest point (1.0) dominates entirely.
+        assert_relative_eq!(idw.evaluate(0.5), 1.0);
+    }
+
+    #[test]
+    fn test_search_radius_all_neighbors_on_boundary() {
+        let points = vec![0.0, 10.0];
+        let values = vec![0.0, 10.0];
+        let idw = IDW::new(points, values).search_radius(5.0);
+
+        // Both points sit exactly on the search radius, so every weight (and their sum) is
+        // zero; falls back to the single nearest point instead of dividing zero by zero.
+        assert_relative_eq!(idw.evaluate(5.0), 10.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_k_nearest_zero() {
+        let points = vec![1.0, 2.0, 3.0];
+        let values = vec![1.0, 2.0, 3.0];
+        IDW::new(points, values).k_nearest(0);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_evaluate_many() {
+        let points = vec![1.0, 2.0, 3.0];
+        let values = vec![1.0, 2.0, 3.0];
+        let idw = IDW::new(points, values);
+
+        let positions = vec![0.0, 1.0, 1.5, 2.0, 2.5, 3.0, 4.0];
+        let results = idw.evaluate_many(&positions);
+        let expected: Vec<f64> = positions.iter().map(|position| idw.evaluate(*position)).collect();
+
+        assert_eq!(results, expected);
+    }
 }
@@ -0,0 +1,251 @@
+use num_traits::Float;
+
+use crate::coord::Coord;
+
+/// A k-d tree over the indices of a points slice, used to answer nearest-neighbor queries
+/// in `O(log N)` instead of scanning every point.
+pub(crate) struct KdTree {
+    root: Option<Box<Node>>,
+}
+
+struct Node {
+    index: usize,
+    axis: usize,
+    left: Option<Box<Node>>,
+    right: Option<Box<Node>>,
+}
+
+impl KdTree {
+    pub(crate) fn build<C, N>(points: &[C]) -> Self
+    where
+        C: Coord<N>,
+        N: Float,
+    {
+        let mut indices: Vec<usize> = (0..points.len()).collect();
+
+        Self {
+            root: build_node(points, &mut indices, 0),
+        }
+    }
+
+    pub(crate) fn nearest<C, N>(&self, points: &[C], query: &C) -> usize
+    where
+        C: Coord<N>,
+        N: Float,
+    {
+        let root = self.root.as_ref().expect("k-d tree must not be empty");
+
+        let mut best_index = root.index;
+        let mut best_distance = points[best_index].distance_squared_to(query);
+
+        nearest_node(
+            points,
+            self.root.as_deref(),
+            query,
+            &mut best_index,
+            &mut best_distance,
+        );
+
+        best_index
+    }
+
+    pub(crate) fn within_radius<C, N>(&self, points: &[C], query: &C, radius: N) -> Vec<usize>
+    where
+        C: Coord<N>,
+        N: Float,
+    {
+        let mut result = Vec::new();
+
+        within_radius_node(
+            points,
+            self.root.as_deref(),
+            query,
+            radius * radius,
+            &mut result,
+        );
+
+        result
+    }
+
+    pub(crate) fn k_nearest<C, N>(&self, points: &[C], query: &C, k: usize) -> Vec<usize>
+    where
+        C: Coord<N>,
+        N: Float,
+    {
+        let mut nearest: Vec<(N, usize)> = Vec::with_capacity(k + 1);
+
+        k_nearest_node(points, self.root.as_deref(), query, k, &mut nearest);
+
+        nearest.into_iter().map(|(_, index)| index).collect()
+    }
+}
+
+fn build_node<C, N>(points: &[C], indices: &mut [usize], depth: usize) -> Option<Box<Node>>
+where
+    C: Coord<N>,
+    N: Float,
+{
+    if indices.is_empty() {
+        return None;
+    }
+
+    let axis = depth % points[indices[0]].dimensions();
+
+    indices.sort_by(|&a, &b| {
+        points[a]
+            .axis(axis)
+            .partial_cmp(&points[b].axis(axis))
+            .unwrap()
+    });
+
+    let mid = indices.len() / 2;
+    let index = indices[mid];
+
+    let (left_indices, rest) = indices.split_at_mut(mid);
+    let (_, right_indices) = rest.split_at_mut(1);
+
+    Some(Box::new(Node {
+        index,
+        axis,
+        left: build_node(points, left_indices, depth + 1),
+        right: build_node(points, right_indices, depth + 1),
+    }))
+}
+
+fn nearest_node<C, N>(
+    points: &[C],
+    node: Option<&Node>,
+    query: &C,
+    best_index: &mut usize,
+    best_distance: &mut N,
+) where
+    C: Coord<N>,
+    N: Float,
+{
+    let Some(node) = node else {
+        return;
+    };
+
+    let distance = points[node.index].distance_squared_to(query);
+
+    if distance < *best_distance {
+        *best_distance = distance;
+        *best_index = node.index;
+    }
+
+    let diff = query.axis(node.axis) - points[node.index].axis(node.axis);
+    let (near, far) = if diff < N::zero() {
+        (&node.left, &node.right)
+    } else {
+        (&node.right, &node.left)
+    };
+
+    nearest_node(points, near.as_deref(), query, best_index, best_distance);
+
+    if diff * diff < *best_distance {
+        nearest_node(points, far.as_deref(), query, best_index, best_distance);
+    }
+}
+
+fn within_radius_node<C, N>(
+    points: &[C],
+    node: Option<&Node>,
+    query: &C,
+    radius_squared: N,
+    result: &mut Vec<usize>,
+) where
+    C: Coord<N>,
+    N: Float,
+{
+    let Some(node) = node else {
+        return;
+    };
+
+    if points[node.index].distance_squared_to(query) <= radius_squared {
+        result.push(node.index);
+    }
+
+    let diff = query.axis(node.axis) - points[node.index].axis(node.axis);
+    let (near, far) = if diff < N::zero() {
+        (&node.left, &node.right)
+    } else {
+        (&node.right, &node.left)
+    };
+
+    within_radius_node(points, near.as_deref(), query, radius_squared, result);
+
+    if diff * diff <= radius_squared {
+        within_radius_node(points, far.as_deref(), query, radius_squared, result);
+    }
+}
+
+fn k_nearest_node<C, N>(
+    points: &[C],
+    node: Option<&Node>,
+    query: &C,
+    k: usize,
+    nearest: &mut Vec<(N, usize)>,
+) where
+    C: Coord<N>,
+    N: Float,
+{
+    let Some(node) = node else {
+        return;
+    };
+
+    let distance = points[node.index].distance_squared_to(query);
+
+    let insert_at = nearest.partition_point(|&(d, _)| d <= distance);
+    nearest.insert(insert_at, (distance, node.index));
+    nearest.truncate(k);
+
+    let diff = query.axis(node.axis) - points[node.index].axis(node.axis);
+    let (near, far) = if diff < N::zero() {
+        (&node.left, &node.right)
+    } else {
+        (&node.right, &node.left)
+    };
+
+    k_nearest_node(points, near.as_deref(), query, k, nearest);
+
+    let worst_distance = nearest.last().map(|&(d, _)| d);
+    if nearest.len() < k || worst_distance.is_none_or(|d| diff * diff <= d) {
+        k_nearest_node(points, far.as_deref(), query, k, nearest);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nearest() {
+        let points = vec![(1.0, 1.0), (2.0, 2.0), (3.5, 2.0), (8.0, 1.0)];
+        let tree = KdTree::build(&points);
+
+        assert_eq!(tree.nearest(&points, &(0.0, 0.0)), 0);
+        assert_eq!(tree.nearest(&points, &(3.5, 2.5)), 2);
+        assert_eq!(tree.nearest(&points, &(8.0, 1.5)), 3);
+    }
+
+    #[test]
+    fn test_within_radius() {
+        let points = vec![(1.0, 1.0), (2.0, 2.0), (3.5, 2.0), (8.0, 1.0)];
+        let tree = KdTree::build(&points);
+
+        let mut within = tree.within_radius(&points, &(2.0, 2.0), 1.49);
+        within.sort();
+        assert_eq!(within, vec![0, 1]);
+
+        assert!(tree.within_radius(&points, &(20.0, 20.0), 1.0).is_empty());
+    }
+
+    #[test]
+    fn test_k_nearest() {
+        let points = vec![(1.0, 1.0), (2.0, 2.0), (3.5, 2.0), (8.0, 1.0)];
+        let tree = KdTree::build(&points);
+
+        assert_eq!(tree.k_nearest(&points, &(2.0, 2.0), 2), vec![1, 0]);
+        assert_eq!(tree.k_nearest(&points, &(2.0, 2.0), 3), vec![1, 0, 2]);
+    }
+}
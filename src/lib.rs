@@ -2,7 +2,8 @@
 //!
 //! An implementation of the [Inverse Distance Weighting (IDW)](https://en.wikipedia.org/wiki/Inverse_distance_weighting) algorithm.
 //!
-//! The crate supports points of 1 to 3 dimension to perform the interpolation.
+//! The crate supports points of 1 to 3 dimension, as well as arrays of any fixed dimension
+//! `[T; D]`, to perform the interpolation.
 //!
 //! The weighted function used in the algorithm is `weightᵢ = 1 / distance(pointᵢ, position)ᵖ`.
 //!
@@ -10,6 +11,17 @@
 //!
 //! The default power parameter used in the algorithm is 2 and can be set by [`IDW::power`].
 //!
+//! Enable the `rayon` feature to evaluate a batch of positions in parallel with
+//! [`IDW::evaluate_many`].
+//!
+//! Values don't have to be a single scalar: anything implementing [`Blend`], such as a tuple
+//! or array of floats, can be interpolated directly so multi-component values (e.g. an RGB
+//! color) only need one weight computation.
+//!
+//! For large scattered datasets, restricting each query to its local neighborhood with
+//! [`IDW::search_radius`] or [`IDW::k_nearest`] switches the interpolator to Franke-Nielson's
+//! Modified Shepard scheme, backed by a k-d tree built over the points.
+//!
 //! # Examples
 //!
 //! ```
@@ -36,6 +48,20 @@
 //!
 //! let result = idw.evaluate((0.5, 0.5, 0.5));
 //!
+//! // N dimension
+//! let points = vec![[0.0, 0.0, 0.0, 0.0], [1.0, 1.0, 1.0, 1.0]];
+//! let values = vec![0.0, 1.0];
+//! let idw = IDW::new(points, values);
+//!
+//! let result = idw.evaluate([0.5, 0.5, 0.5, 0.5]);
+//!
+//! // Vector-valued (e.g. RGB colors), driven by a single weight computation
+//! let points = vec![0.0, 1.0];
+//! let values = vec![(0.0, 0.0, 0.0), (1.0, 1.0, 1.0)];
+//! let idw = IDW::new(points, values);
+//!
+//! let result = idw.evaluate(0.5);
+//!
 //! // Customize
 //! let points = vec![0.0, 1.0];
 //! let values = vec![0.0, 1.0];
@@ -44,9 +70,12 @@
 //!     .weighted_function(|weight| (1.0 + (4.0 * std::f64::consts::PI * weight).sin()) * 0.5);
 //! ```
 
+mod blend;
 mod coord;
 mod idw;
+mod kdtree;
 
+pub use blend::Blend;
 pub use idw::IDW;
 
 #[cfg(test)]